@@ -1,11 +1,221 @@
+use std::sync::OnceLock;
+
 use pyo3::prelude::*;
 use pyo3::types::{PyAny, PyDict, PyString};
+use regex::Regex;
 
 use super::Validator;
 use crate::errors::{context, err_val_error, ErrorKind, ValResult};
+use crate::py_error;
 use crate::standalone_validators::validate_str;
 use crate::utils::{dict_get, RegexPattern};
 
+#[derive(Debug, Clone, Copy)]
+enum LengthUnit {
+    Chars,
+    Bytes,
+}
+
+impl LengthUnit {
+    fn count(&self, str: &str) -> usize {
+        match self {
+            LengthUnit::Chars => str.chars().count(),
+            LengthUnit::Bytes => str.len(),
+        }
+    }
+
+    fn as_str(&self) -> &'static str {
+        match self {
+            LengthUnit::Chars => "chars",
+            LengthUnit::Bytes => "bytes",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum StripMode {
+    Both,
+    Left,
+    Right,
+}
+
+impl StripMode {
+    fn from_str(mode: &str) -> Option<Self> {
+        match mode {
+            "both" => Some(StripMode::Both),
+            "left" => Some(StripMode::Left),
+            "right" => Some(StripMode::Right),
+            _ => None,
+        }
+    }
+
+    /// Trim `str` on the configured side(s) using `pat` as the predicate for
+    /// which characters to remove.
+    fn strip<'a, P: Fn(char) -> bool + Copy>(&self, str: &'a str, pat: P) -> &'a str {
+        match self {
+            StripMode::Both => str.trim_matches(pat),
+            StripMode::Left => str.trim_start_matches(pat),
+            StripMode::Right => str.trim_end_matches(pat),
+        }
+    }
+}
+
+/// Lower-case, collapse every run of non-`[A-Za-z0-9]` characters to a single
+/// dash, and trim leading/trailing dashes. The two regexes are compiled once
+/// and reused to avoid per-call cost.
+fn slugify(str: &str) -> String {
+    static SEPARATOR: OnceLock<Regex> = OnceLock::new();
+    static EDGE_DASHES: OnceLock<Regex> = OnceLock::new();
+    let separator = SEPARATOR.get_or_init(|| Regex::new(r"[^A-Za-z0-9]+").unwrap());
+    let edge_dashes = EDGE_DASHES.get_or_init(|| Regex::new(r"^-+|-+$").unwrap());
+    let lowered = str.to_lowercase();
+    let dashed = separator.replace_all(&lowered, "-");
+    edge_dashes.replace_all(&dashed, "").into_owned()
+}
+
+/// Upper-case the first character of every whitespace-separated word.
+fn title_case(str: &str) -> String {
+    let mut result = String::with_capacity(str.len());
+    let mut at_word_start = true;
+    for c in str.chars() {
+        if c.is_whitespace() {
+            at_word_start = true;
+            result.push(c);
+        } else if at_word_start {
+            result.extend(c.to_uppercase());
+            at_word_start = false;
+        } else {
+            result.extend(c.to_lowercase());
+        }
+    }
+    result
+}
+
+#[derive(Debug, Clone, Copy)]
+enum StrFormat {
+    Email,
+    Url,
+    Ipv4,
+    Ipv6,
+    CreditCard,
+}
+
+impl StrFormat {
+    fn from_str(format: &str) -> Option<Self> {
+        match format {
+            "email" => Some(StrFormat::Email),
+            "url" => Some(StrFormat::Url),
+            "ipv4" => Some(StrFormat::Ipv4),
+            "ipv6" => Some(StrFormat::Ipv6),
+            "credit_card" => Some(StrFormat::CreditCard),
+            _ => None,
+        }
+    }
+
+    fn is_valid(&self, str: &str) -> bool {
+        match self {
+            StrFormat::Email => is_valid_email(str),
+            StrFormat::Url => is_valid_url(str),
+            StrFormat::Ipv4 => is_valid_ipv4(str),
+            StrFormat::Ipv6 => is_valid_ipv6(str),
+            StrFormat::CreditCard => is_valid_credit_card(str),
+        }
+    }
+
+    fn error_kind(&self) -> ErrorKind {
+        match self {
+            StrFormat::Email => ErrorKind::StrInvalidEmail,
+            StrFormat::Url => ErrorKind::StrInvalidUrl,
+            StrFormat::Ipv4 | StrFormat::Ipv6 => ErrorKind::StrInvalidIp,
+            StrFormat::CreditCard => ErrorKind::StrInvalidCreditCard,
+        }
+    }
+}
+
+/// Minimal `local@domain` check: exactly one `@`, non-empty local part, and a
+/// domain that contains a dot with non-empty labels on either side.
+fn is_valid_email(str: &str) -> bool {
+    let (local, domain) = match str.split_once('@') {
+        Some(parts) => parts,
+        None => return false,
+    };
+    if local.is_empty() || domain.contains('@') {
+        return false;
+    }
+    match domain.rsplit_once('.') {
+        Some((host, tld)) => !host.is_empty() && !tld.is_empty(),
+        None => false,
+    }
+}
+
+/// Accept an `http(s)`/`ftp` URL with a non-empty authority component.
+fn is_valid_url(str: &str) -> bool {
+    for scheme in ["http://", "https://", "ftp://"] {
+        if let Some(rest) = str.strip_prefix(scheme) {
+            let authority = rest.split(['/', '?', '#']).next().unwrap_or("");
+            return !authority.is_empty();
+        }
+    }
+    false
+}
+
+fn is_valid_ipv4(str: &str) -> bool {
+    let mut octets = 0;
+    for part in str.split('.') {
+        octets += 1;
+        if part.is_empty() || part.len() > 3 || !part.bytes().all(|b| b.is_ascii_digit()) {
+            return false;
+        }
+        if part.parse::<u16>().map_or(true, |n| n > 255) {
+            return false;
+        }
+    }
+    octets == 4
+}
+
+/// Validate an IPv6 address, allowing a single `::` compression group.
+fn is_valid_ipv6(str: &str) -> bool {
+    let compressed = str.matches("::").count();
+    if compressed > 1 {
+        return false;
+    }
+    let is_group = |group: &str| -> bool {
+        !group.is_empty() && group.len() <= 4 && group.bytes().all(|b| b.is_ascii_hexdigit())
+    };
+    if compressed == 1 {
+        let (head, tail) = str.split_once("::").unwrap();
+        let head_groups: Vec<&str> = if head.is_empty() { vec![] } else { head.split(':').collect() };
+        let tail_groups: Vec<&str> = if tail.is_empty() { vec![] } else { tail.split(':').collect() };
+        if head_groups.len() + tail_groups.len() > 7 {
+            return false;
+        }
+        head_groups.iter().chain(tail_groups.iter()).all(|g| is_group(g))
+    } else {
+        let groups: Vec<&str> = str.split(':').collect();
+        groups.len() == 8 && groups.iter().all(|g| is_group(g))
+    }
+}
+
+/// Luhn checksum over 12–19 digit card numbers, ignoring separators.
+fn is_valid_credit_card(str: &str) -> bool {
+    let digits: Vec<u32> = str.chars().filter_map(|c| c.to_digit(10)).collect();
+    if digits.len() < 12 || digits.len() > 19 {
+        return false;
+    }
+    let mut sum = 0;
+    for (i, digit) in digits.iter().rev().enumerate() {
+        let mut d = *digit;
+        if i % 2 == 1 {
+            d *= 2;
+            if d > 9 {
+                d -= 9;
+            }
+        }
+        sum += d;
+    }
+    sum % 10 == 0
+}
+
 #[derive(Debug, Clone)]
 pub struct SimpleStrValidator;
 
@@ -18,6 +228,17 @@ impl Validator for SimpleStrValidator {
             && dict.get_item("strip_whitespace").is_none()
             && dict.get_item("to_lower").is_none()
             && dict.get_item("to_upper").is_none()
+            && dict.get_item("length_unit").is_none()
+            && dict.get_item("format").is_none()
+            && dict.get_item("strip_mode").is_none()
+            && dict.get_item("strip_chars").is_none()
+            && dict.get_item("to_slug").is_none()
+            && dict.get_item("to_title").is_none()
+            && dict.get_item("length").is_none()
+            && dict.get_item("equal").is_none()
+            && dict.get_item("contains").is_none()
+            && dict.get_item("not_contains").is_none()
+            && dict.get_item("non_control_character").is_none()
     }
 
     fn build(_dict: &PyDict) -> PyResult<Self> {
@@ -39,9 +260,19 @@ pub struct FullStrValidator {
     pattern: Option<RegexPattern>,
     max_length: Option<usize>,
     min_length: Option<usize>,
+    length: Option<usize>,
+    length_unit: LengthUnit,
+    contains: Option<String>,
+    not_contains: Option<String>,
+    non_control_character: bool,
+    format: Option<StrFormat>,
     strip_whitespace: bool,
+    strip_mode: StripMode,
+    strip_chars: Option<String>,
     to_lower: bool,
     to_upper: bool,
+    to_slug: bool,
+    to_title: bool,
 }
 
 impl Validator for FullStrValidator {
@@ -56,40 +287,118 @@ impl Validator for FullStrValidator {
         };
         let min_length = dict_get!(dict, "min_length", usize);
         let max_length = dict_get!(dict, "max_length", usize);
+        // `equal` is accepted as an alias of `length` for parity with other crates.
+        let length = dict_get!(dict, "length", usize).or_else(|| dict_get!(dict, "equal", usize));
+        let contains = dict_get!(dict, "contains", String);
+        let not_contains = dict_get!(dict, "not_contains", String);
+        let non_control_character = dict_get!(dict, "non_control_character", bool).unwrap_or(false);
+        let length_unit = match dict_get!(dict, "length_unit", String).as_deref() {
+            Some("bytes") => LengthUnit::Bytes,
+            Some("chars") | None => LengthUnit::Chars,
+            Some(other) => return py_error!("Invalid length_unit: {}", other),
+        };
+        let format = match dict_get!(dict, "format", String) {
+            Some(f) => match StrFormat::from_str(&f) {
+                Some(format) => Some(format),
+                None => return py_error!("Invalid format: {}", f),
+            },
+            None => None,
+        };
         let strip_whitespace = dict_get!(dict, "strip_whitespace", bool);
+        let strip_mode = match dict_get!(dict, "strip_mode", String) {
+            Some(mode) => match StripMode::from_str(&mode) {
+                Some(strip_mode) => strip_mode,
+                None => return py_error!("Invalid strip_mode: {}", mode),
+            },
+            None => StripMode::Both,
+        };
+        let strip_chars = dict_get!(dict, "strip_chars", String);
         let to_lower = dict_get!(dict, "to_lower", bool);
         let to_upper = dict_get!(dict, "to_upper", bool);
+        let to_slug = dict_get!(dict, "to_slug", bool);
+        let to_title = dict_get!(dict, "to_title", bool);
 
         Ok(Self {
             pattern,
             min_length,
             max_length,
+            length,
+            length_unit,
+            contains,
+            not_contains,
+            non_control_character,
+            format,
             strip_whitespace: strip_whitespace.unwrap_or(false),
+            strip_mode,
+            strip_chars,
             to_lower: to_lower.unwrap_or(false),
             to_upper: to_upper.unwrap_or(false),
+            to_slug: to_slug.unwrap_or(false),
+            to_title: to_title.unwrap_or(false),
         })
     }
 
     fn validate(&self, py: Python, input: &PyAny, _data: &PyDict) -> ValResult<PyObject> {
         let mut str = validate_str(py, input)?;
+        let length = self.length_unit.count(&str);
+        if let Some(exact_length) = self.length {
+            if length != exact_length {
+                return err_val_error!(
+                    py,
+                    str,
+                    kind = ErrorKind::StrWrongLength,
+                    context = context!("length" => exact_length, "unit" => self.length_unit.as_str())
+                );
+            }
+        }
         if let Some(min_length) = self.min_length {
-            if str.len() < min_length {
+            if length < min_length {
                 // return py_error!("{} is shorter than {}", str, min_length);
                 return err_val_error!(
                     py,
                     str,
                     kind = ErrorKind::StrTooShort,
-                    context = context!("min_length" => min_length)
+                    context = context!("min_length" => min_length, "unit" => self.length_unit.as_str())
                 );
             }
         }
         if let Some(max_length) = self.max_length {
-            if str.len() > max_length {
+            if length > max_length {
                 return err_val_error!(
                     py,
                     str,
                     kind = ErrorKind::StrTooLong,
-                    context = context!("max_length" => max_length)
+                    context = context!("max_length" => max_length, "unit" => self.length_unit.as_str())
+                );
+            }
+        }
+        if let Some(contains) = &self.contains {
+            if !str.contains(contains.as_str()) {
+                return err_val_error!(
+                    py,
+                    str,
+                    kind = ErrorKind::StrMissingSubstring,
+                    context = context!("contains" => contains.clone())
+                );
+            }
+        }
+        if let Some(not_contains) = &self.not_contains {
+            if str.contains(not_contains.as_str()) {
+                return err_val_error!(
+                    py,
+                    str,
+                    kind = ErrorKind::StrForbiddenSubstring,
+                    context = context!("not_contains" => not_contains.clone())
+                );
+            }
+        }
+        if self.non_control_character {
+            if let Some((position, _)) = str.char_indices().find(|(_, c)| c.is_control()) {
+                return err_val_error!(
+                    py,
+                    str,
+                    kind = ErrorKind::StrHasControlChars,
+                    context = context!("position" => position)
                 );
             }
         }
@@ -103,12 +412,28 @@ impl Validator for FullStrValidator {
                 );
             }
         }
+        if let Some(format) = &self.format {
+            if !format.is_valid(&str) {
+                return err_val_error!(py, str, kind = format.error_kind());
+            }
+        }
 
-        if self.strip_whitespace {
-            str = str.trim().to_string();
+        // Transforms are applied in a fixed, documented order: strip first, then
+        // a single case/slug transform.
+        if let Some(strip_chars) = &self.strip_chars {
+            str = self
+                .strip_mode
+                .strip(&str, |c| strip_chars.contains(c))
+                .to_string();
+        } else if self.strip_whitespace {
+            str = self.strip_mode.strip(&str, char::is_whitespace).to_string();
         }
 
-        if self.to_lower {
+        if self.to_slug {
+            str = slugify(&str);
+        } else if self.to_title {
+            str = title_case(&str);
+        } else if self.to_lower {
             str = str.to_lowercase()
         } else if self.to_upper {
             str = str.to_uppercase()